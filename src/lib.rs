@@ -1,6 +1,8 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 
+extern crate alloc;
+
 /// Module that is meant to store all specializations of the yet non-expressible `CartProd`
 /// variadic generic type. Check the [crate] documentation for more information.
 pub mod specs;
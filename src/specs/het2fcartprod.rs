@@ -0,0 +1,185 @@
+use core::iter::{FusedIterator, Peekable};
+
+/// Two-fold [Cartesian product] of [iterators] that are "heterogeneous" in the sense that they
+/// may iterate over *different* item types, yielding `(A, B)` tuples instead of a `[Item; 2]`
+/// array. This mirrors [itertools' `iproduct!`] for the two-iterator case.
+///
+/// ## Examples
+///
+/// ### Manual iteration
+///
+/// ```
+/// use cart_prod::specs::Het2FCartProd;
+///
+/// let it1 = 0..=1;
+/// let it2 = ["a", "b"].into_iter();
+///
+/// let mut it = Het2FCartProd::new(it1, it2);
+///
+/// assert_eq!(it.next(), Some((0, "a")));
+/// assert_eq!(it.next(), Some((0, "b")));
+/// assert_eq!(it.next(), Some((1, "a")));
+/// assert_eq!(it.next(), Some((1, "b")));
+/// assert_eq!(it.next(), None);
+/// ```
+///
+/// ### For loop with pattern matching
+///
+/// ```
+/// use cart_prod::specs::Het2FCartProd;
+/// use core::fmt::Write;
+///
+/// let it1 = 0..=1;
+/// let it2 = ["a", "b"].into_iter();
+///
+/// let mut s = String::new();
+///
+/// for (el1, el2) in Het2FCartProd::new(it1, it2) {
+///    // The panic is intentional to keep the example simple.
+///    writeln!(s, "{el1} {el2}").unwrap();
+/// }
+///
+/// assert_eq!(s, "0 a\n0 b\n1 a\n1 b\n");
+/// ```
+///
+/// [Cartesian product]: https://en.wikipedia.org/wiki/Cartesian_product
+/// [iterators]: https://doc.rust-lang.org/book/ch13-02-iterators.html
+/// [itertools' `iproduct!`]: https://docs.rs/itertools/latest/itertools/macro.iproduct.html
+pub struct Het2FCartProd<A, B, I1, I2>
+where
+    A: Clone,
+    I1: Iterator<Item = A>,
+    I2: Iterator<Item = B> + Clone,
+{
+    curr_it1: Peekable<I1>,
+    curr_it2: I2,
+    // Original iterator 1 is not required because
+    // the traversal over it1 happens only once.
+    orig_it2: I2,
+}
+
+impl<A, B, I1, I2> Het2FCartProd<A, B, I1, I2>
+where
+    A: Clone,
+    I1: Iterator<Item = A>,
+    I2: Iterator<Item = B> + Clone,
+{
+    /// Creates a new [`Het2FCartProd`] from two iterators.
+    pub fn new(it1: I1, it2: I2) -> Self {
+        Self {
+            curr_it1: it1.peekable(),
+            curr_it2: it2.clone(),
+            orig_it2: it2,
+        }
+    }
+}
+
+impl<A, B, I1, I2> Iterator for Het2FCartProd<A, B, I1, I2>
+where
+    A: Clone,
+    I1: Iterator<Item = A>,
+    I2: Iterator<Item = B> + Clone,
+{
+    type Item = (A, B);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut el1 = self.curr_it1.peek()?.clone();
+        let el2 = match self.curr_it2.next() {
+            Some(el2) => el2,
+            None => {
+                let _ = self.curr_it1.next()?;
+                el1 = self.curr_it1.peek()?.clone();
+                self.curr_it2 = self.orig_it2.clone();
+                self.curr_it2.next()?
+            }
+        };
+        Some((el1, el2))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (min1, max1) = self.curr_it1.size_hint();
+        let (min2, max2) = self.curr_it2.size_hint();
+        let min = min1.saturating_mul(min2);
+        let max = match (max1, max2) {
+            (Some(max1), Some(max2)) => max1.checked_mul(max2),
+            _ => None,
+        };
+        (min, max)
+    }
+}
+
+/// `next` returns `None` as soon as `it1` is exhausted and never touches `it1`/`it2` again
+/// afterwards, so a [`Het2FCartProd`] never yields `Some` after `None`.
+impl<A, B, I1, I2> FusedIterator for Het2FCartProd<A, B, I1, I2>
+where
+    A: Clone,
+    I1: Iterator<Item = A>,
+    I2: Iterator<Item = B> + Clone,
+{
+}
+
+impl<A, B, I1, I2> ExactSizeIterator for Het2FCartProd<A, B, I1, I2>
+where
+    A: Clone,
+    I1: Iterator<Item = A> + ExactSizeIterator,
+    I2: Iterator<Item = B> + Clone + ExactSizeIterator,
+{
+    fn len(&self) -> usize {
+        let r2 = self.orig_it2.len();
+        let rows_left = self.curr_it1.len();
+        if rows_left == 0 {
+            0
+        } else {
+            self.curr_it2.len() + (rows_left - 1) * r2
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_empty() {
+        let it1 = 0..0;
+        let it2 = ["a", "b"].into_iter();
+        let mut it = Het2FCartProd::new(it1, it2);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_size_hint_simple() {
+        let it1 = 0..2;
+        let it2 = ["a", "b"].into_iter();
+        let it = Het2FCartProd::new(it1, it2);
+        assert_eq!(it.size_hint(), (4, Some(4)));
+    }
+
+    #[test]
+    fn test_size_hint_empty() {
+        let it1 = 0..0;
+        let it2 = ["a", "b"].into_iter();
+        let it = Het2FCartProd::new(it1, it2);
+        assert_eq!(it.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_len_shrinks_as_consumed() {
+        let it1 = 0..2;
+        let it2 = ["a", "b"].into_iter();
+        let mut it = Het2FCartProd::new(it1, it2);
+        assert_eq!(it.len(), 4);
+        it.next();
+        assert_eq!(it.len(), 3);
+    }
+
+    #[test]
+    fn test_fused_after_exhaustion() {
+        let it1 = 0..1;
+        let it2 = ["a"].into_iter();
+        let mut it = Het2FCartProd::new(it1, it2);
+        assert_eq!(it.next(), Some((0, "a")));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+    }
+}
@@ -0,0 +1,250 @@
+use core::iter::{FusedIterator, Peekable};
+
+/// Three-fold [Cartesian product] of [iterators] that are "heterogeneous" in the sense that
+/// they may iterate over *different* item types, yielding `(A, B, C)` tuples instead of a
+/// `[Item; 3]` array. This mirrors [itertools' `iproduct!`] for the three-iterator case.
+///
+/// ## Examples
+///
+/// ### Manual iteration
+///
+/// ```
+/// use cart_prod::specs::Het3FCartProd;
+///
+/// let it1 = 0..=1;
+/// let it2 = ["a", "b"].into_iter();
+/// let it3 = [true, false].into_iter();
+///
+/// let mut it = Het3FCartProd::new(it1, it2, it3);
+///
+/// assert_eq!(it.next(), Some((0, "a", true)));
+/// assert_eq!(it.next(), Some((0, "a", false)));
+/// assert_eq!(it.next(), Some((0, "b", true)));
+/// assert_eq!(it.next(), Some((0, "b", false)));
+/// assert_eq!(it.next(), Some((1, "a", true)));
+/// assert_eq!(it.next(), Some((1, "a", false)));
+/// assert_eq!(it.next(), Some((1, "b", true)));
+/// assert_eq!(it.next(), Some((1, "b", false)));
+/// assert_eq!(it.next(), None);
+/// ```
+///
+/// ### For loop with pattern matching
+///
+/// ```
+/// use cart_prod::specs::Het3FCartProd;
+/// use core::fmt::Write;
+///
+/// let it1 = 0..=1;
+/// let it2 = ["a", "b"].into_iter();
+/// let it3 = [true, false].into_iter();
+///
+/// let mut s = String::new();
+///
+/// for (el1, el2, el3) in Het3FCartProd::new(it1, it2, it3) {
+///    // The panic is intentional to keep the example simple.
+///    writeln!(s, "{el1} {el2} {el3}").unwrap();
+/// }
+///
+/// assert_eq!(
+///     s,
+///     "0 a true\n0 a false\n0 b true\n0 b false\n1 a true\n1 a false\n1 b true\n1 b false\n"
+/// );
+/// ```
+///
+/// [Cartesian product]: https://en.wikipedia.org/wiki/Cartesian_product
+/// [iterators]: https://doc.rust-lang.org/book/ch13-02-iterators.html
+/// [itertools' `iproduct!`]: https://docs.rs/itertools/latest/itertools/macro.iproduct.html
+pub struct Het3FCartProd<A, B, C, I1, I2, I3>
+where
+    A: Clone,
+    B: Clone,
+    I1: Iterator<Item = A>,
+    I2: Iterator<Item = B> + Clone,
+    I3: Iterator<Item = C> + Clone,
+{
+    curr_it1: Peekable<I1>,
+    curr_it2: Peekable<I2>,
+    curr_it3: I3,
+    // Original iterator 1 is not required because
+    // the traversal over it1 happens only once.
+    orig_it2: I2,
+    orig_it3: I3,
+}
+
+impl<A, B, C, I1, I2, I3> Het3FCartProd<A, B, C, I1, I2, I3>
+where
+    A: Clone,
+    B: Clone,
+    I1: Iterator<Item = A>,
+    I2: Iterator<Item = B> + Clone,
+    I3: Iterator<Item = C> + Clone,
+{
+    /// Creates a new [`Het3FCartProd`] from three iterators.
+    pub fn new(it1: I1, it2: I2, it3: I3) -> Self {
+        Self {
+            curr_it1: it1.peekable(),
+            curr_it2: it2.clone().peekable(),
+            orig_it2: it2,
+            curr_it3: it3.clone(),
+            orig_it3: it3,
+        }
+    }
+}
+
+impl<A, B, C, I1, I2, I3> Iterator for Het3FCartProd<A, B, C, I1, I2, I3>
+where
+    A: Clone,
+    B: Clone,
+    I1: Iterator<Item = A>,
+    I2: Iterator<Item = B> + Clone,
+    I3: Iterator<Item = C> + Clone,
+{
+    type Item = (A, B, C);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let el1 = self.curr_it1.peek()?.clone();
+        let mut el2 = self.curr_it2.peek()?.clone();
+        if let Some(el3) = self.curr_it3.next() {
+            return Some((el1, el2, el3));
+        }
+        drop(self.curr_it2.next()?);
+        if let Some(el2) = self.curr_it2.peek().cloned() {
+            self.curr_it3 = self.orig_it3.clone();
+            return Some((el1, el2, self.curr_it3.next()?));
+        }
+        drop(self.curr_it1.next()?);
+        if let Some(el1) = self.curr_it1.peek().cloned() {
+            self.curr_it3 = self.orig_it3.clone();
+            self.curr_it2 = self.orig_it2.clone().peekable();
+            el2 = self.curr_it2.peek()?.clone();
+            return Some((el1, el2, self.curr_it3.next()?));
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (min1, max1) = self.curr_it1.size_hint();
+        let (min2, max2) = self.curr_it2.size_hint();
+        let (min3, max3) = self.curr_it3.size_hint();
+        let minima = [min1, min2, min3];
+        let maxima = [max1, max2, max3];
+        let min = minima.iter().copied().fold(1usize, |prod, x| {
+            prod.saturating_mul(x)
+        });
+        let max = maxima.iter().copied().try_fold(1usize, |prod, opt_x| {
+            match opt_x {
+                Some(x) => prod.checked_mul(x),
+                None => None,
+            }
+        });
+        (min, max)
+    }
+}
+
+/// `next` returns `None` as soon as `it1` is exhausted and never touches `it1`/`it2`/`it3` again
+/// afterwards, so a [`Het3FCartProd`] never yields `Some` after `None`.
+impl<A, B, C, I1, I2, I3> FusedIterator for Het3FCartProd<A, B, C, I1, I2, I3>
+where
+    A: Clone,
+    B: Clone,
+    I1: Iterator<Item = A>,
+    I2: Iterator<Item = B> + Clone,
+    I3: Iterator<Item = C> + Clone,
+{
+}
+
+impl<A, B, C, I1, I2, I3> ExactSizeIterator for Het3FCartProd<A, B, C, I1, I2, I3>
+where
+    A: Clone,
+    B: Clone,
+    I1: Iterator<Item = A> + ExactSizeIterator,
+    I2: Iterator<Item = B> + Clone + ExactSizeIterator,
+    I3: Iterator<Item = C> + Clone + ExactSizeIterator,
+{
+    fn len(&self) -> usize {
+        let r2 = self.orig_it2.len();
+        let r3 = self.orig_it3.len();
+        let rows_left = self.curr_it1.len();
+        if rows_left == 0 {
+            0
+        } else {
+            let cols_left = self.curr_it2.len();
+            if cols_left == 0 {
+                (rows_left - 1) * r2 * r3
+            } else {
+                self.curr_it3.len() + (cols_left - 1) * r3 + (rows_left - 1) * r2 * r3
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple() {
+        let it1 = 0..=1;
+        let it2 = ["a", "b"].into_iter();
+        let it3 = [true, false].into_iter();
+        let mut it = Het3FCartProd::new(it1, it2, it3);
+        assert_eq!(it.next(), Some((0, "a", true)));
+        assert_eq!(it.next(), Some((0, "a", false)));
+        assert_eq!(it.next(), Some((0, "b", true)));
+        assert_eq!(it.next(), Some((0, "b", false)));
+        assert_eq!(it.next(), Some((1, "a", true)));
+        assert_eq!(it.next(), Some((1, "a", false)));
+        assert_eq!(it.next(), Some((1, "b", true)));
+        assert_eq!(it.next(), Some((1, "b", false)));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_empty() {
+        let it1 = 0..=1;
+        let it2 = ["a", "b"].into_iter();
+        let it3 = core::iter::empty::<bool>();
+        let mut it = Het3FCartProd::new(it1, it2, it3);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_size_hint_simple() {
+        let it1 = 0..2;
+        let it2 = ["a", "b"].into_iter();
+        let it3 = [true, false, true].into_iter();
+        let it = Het3FCartProd::new(it1, it2, it3);
+        assert_eq!(it.size_hint(), (12, Some(12)));
+    }
+
+    #[test]
+    fn test_size_hint_empty() {
+        let it1 = 0..2;
+        let it2 = ["a", "b"].into_iter();
+        let it3 = core::iter::empty::<bool>();
+        let it = Het3FCartProd::new(it1, it2, it3);
+        assert_eq!(it.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_len_shrinks_as_consumed() {
+        let it1 = 0..2;
+        let it2 = ["a", "b"].into_iter();
+        let it3 = [true, false].into_iter();
+        let mut it = Het3FCartProd::new(it1, it2, it3);
+        assert_eq!(it.len(), 8);
+        it.next();
+        assert_eq!(it.len(), 7);
+    }
+
+    #[test]
+    fn test_fused_after_exhaustion() {
+        let it1 = 0..1;
+        let it2 = ["a"].into_iter();
+        let it3 = [true].into_iter();
+        let mut it = Het3FCartProd::new(it1, it2, it3);
+        assert_eq!(it.next(), Some((0, "a", true)));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+    }
+}
@@ -1,4 +1,4 @@
-use core::iter::Peekable;
+use core::iter::{FusedIterator, Peekable};
 
 /// Two-fold [Cartesian product] of [iterators] that are "homogeneous" in the sense that
 /// they iterate over the same type of items. Notice that if the elements in the iterators repeat,
@@ -55,6 +55,17 @@ where
     // Original iterator 1 is not required because
     // the traversal over it1 happens only once.
     orig_it2: I2,
+    // Back-traversal state, populated lazily on the first `next_back` call. `back_el1` caches
+    // the it1 value for a row claimed from the back (distinct from `curr_it1`'s own row), since
+    // `Peekable` has no way to peek from the back. `None` means back traversal is still sharing
+    // `curr_it1`'s current row (and, by extension, `curr_it2`) with the forward cursor.
+    back_el1: Option<Item>,
+    back_it2: Option<I2>,
+    // Number of elements `next_back` has taken directly from the back of `curr_it2` while it is
+    // shared with the forward cursor (i.e. while `curr_it1.len() == 1` and no separate `back_it2`
+    // row is claimed). `r2 - curr_it2.len()` alone conflates front and back consumption once this
+    // is nonzero, so `nth`/`advance_by` subtract it back out to recover the front-only offset.
+    shared_back_taken: usize,
 }
 
 impl<Item, I1, I2> Hom2FCartProd<Item, I1, I2>
@@ -69,6 +80,9 @@ where
             curr_it1: it1.peekable(),
             curr_it2: it2.clone(),
             orig_it2: it2,
+            back_el1: None,
+            back_it2: None,
+            shared_back_taken: 0,
         }
     }
 }
@@ -89,6 +103,7 @@ where
                 let _ = self.curr_it1.next()?;
                 el1 = self.curr_it1.peek()?.clone();
                 self.curr_it2 = self.orig_it2.clone();
+                self.shared_back_taken = 0;
                 self.curr_it2.next()?
             }
         };
@@ -107,6 +122,162 @@ where
     }
 }
 
+/// `next` returns `None` as soon as `it1` is exhausted and never touches `it1`/`it2` again
+/// afterwards, so a [`Hom2FCartProd`] never yields `Some` after `None`.
+impl<Item, I1, I2> FusedIterator for Hom2FCartProd<Item, I1, I2>
+where
+    Item: Clone,
+    I1: Iterator<Item=Item>,
+    I2: Iterator<Item=Item> + Clone,
+{
+}
+
+impl<Item, I1, I2> ExactSizeIterator for Hom2FCartProd<Item, I1, I2>
+where
+    Item: Clone,
+    I1: Iterator<Item=Item> + ExactSizeIterator,
+    I2: Iterator<Item=Item> + Clone + ExactSizeIterator,
+{
+    fn len(&self) -> usize {
+        self.remaining()
+    }
+}
+
+impl<Item, I1, I2> DoubleEndedIterator for Hom2FCartProd<Item, I1, I2>
+where
+    Item: Clone,
+    I1: Iterator<Item=Item> + ExactSizeIterator + DoubleEndedIterator,
+    I2: Iterator<Item=Item> + Clone + ExactSizeIterator + DoubleEndedIterator,
+{
+    /// Emits the lexicographically-greatest remaining tuple by taking the last element of each
+    /// axis, then decrements `it2` from the end, carrying into `it1` when `it2` is exhausted.
+    ///
+    /// As long as `it1`/`it2` are driven consistently through this cursor (either always via
+    /// `next`/`next_back` on the same [`Hom2FCartProd`]), standard library and [`Peekable`]
+    /// `DoubleEndedIterator` semantics guarantee the forward and backward cursors meet in the
+    /// middle without double-yielding, even while sharing `it1`'s current row with the forward
+    /// cursor (`curr_it1`/`curr_it2` are themselves driven from both ends in that case).
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.back_el1.is_none() {
+                match self.curr_it1.len() {
+                    0 => return None,
+                    1 => {
+                        // Only one it1 row remains: share it (and its it2 axis) with the
+                        // forward cursor, relying on `next`/`next_back` meeting correctly.
+                        let el1 = self.curr_it1.peek()?.clone();
+                        let el2 = self.curr_it2.next_back()?;
+                        self.shared_back_taken += 1;
+                        return Some([el1, el2]);
+                    }
+                    _ => {
+                        // Claim a fresh row strictly to the right of the forward cursor's row.
+                        let el1 = self.curr_it1.next_back()?;
+                        self.back_el1 = Some(el1);
+                        self.back_it2 = Some(self.orig_it2.clone());
+                    }
+                }
+            } else {
+                let el1 = self.back_el1.clone().expect("checked is_none above");
+                match self.back_it2.as_mut().expect("set alongside back_el1").next_back() {
+                    Some(el2) => return Some([el1, el2]),
+                    None => {
+                        // This claimed row is fully drained; release it and re-evaluate.
+                        self.back_el1 = None;
+                        self.back_it2 = None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<Item, I1, I2> Hom2FCartProd<Item, I1, I2>
+where
+    Item: Clone,
+    I1: Iterator<Item=Item> + ExactSizeIterator,
+    I2: Iterator<Item=Item> + Clone + ExactSizeIterator,
+{
+    /// Number of elements remaining, computed from the two axes' exact lengths instead of by
+    /// counting. Used by [`Hom2FCartProd::advance_by`] and by [`ExactSizeIterator::len`].
+    ///
+    /// `back_it2`, when present, tracks a row claimed from the back by [`next_back`](
+    /// Hom2FCartProd::next_back) that `curr_it1`'s length no longer accounts for; its own
+    /// remaining elements are added back in.
+    fn remaining(&self) -> usize {
+        let r2 = self.orig_it2.len();
+        let rows_left = self.curr_it1.len();
+        let front_remaining = if rows_left == 0 {
+            0
+        } else {
+            self.curr_it2.len() + (rows_left - 1) * r2
+        };
+        let back_remaining = self.back_it2.as_ref().map_or(0, ExactSizeIterator::len);
+        front_remaining + back_remaining
+    }
+
+    /// O(1) equivalent of calling [`Iterator::next`] `n` times and keeping the last result,
+    /// available whenever both axes are [`ExactSizeIterator`].
+    ///
+    /// This is an inherent method, not an override of [`Iterator::nth`], so it only speeds up
+    /// direct calls to `nth`/[`advance_by`](Hom2FCartProd::advance_by) — `.skip(n)`/`.step_by(n)`
+    /// go through the default trait method and still drive the axes with plain `next` calls.
+    ///
+    /// Treats the product as a mixed-radix number with `it2`'s length as the radix: the skip
+    /// count is decomposed into a carry into `it1` (advanced directly, since it is never reset)
+    /// and an offset into `it2`. `d2_start`/`r2 - curr_it2.len() - shared_back_taken` recovers how
+    /// far `it2` has been consumed from the *front* alone, since `next_back` may also have taken
+    /// elements from its back while it was shared with the forward cursor.
+    pub fn nth(&mut self, n: usize) -> Option<[Item; 2]> {
+        let r2 = self.orig_it2.len();
+        if r2 == 0 {
+            return None;
+        }
+        let d2_start = r2 - self.curr_it2.len() - self.shared_back_taken;
+        let rem = d2_start.checked_add(n)?;
+        let carry = rem / r2;
+        let d2 = rem % r2;
+
+        let el2 = if carry > 0 {
+            self.curr_it1.nth(carry - 1)?;
+            self.curr_it1.peek()?;
+            self.curr_it2 = self.orig_it2.clone();
+            self.shared_back_taken = 0;
+            self.curr_it2.nth(d2)?
+        } else {
+            self.curr_it1.peek()?;
+            // Same it1 row: advance it2 from wherever it currently is, not from a fresh clone,
+            // so any already-claimed back state in a shared it2 stays intact.
+            self.curr_it2.nth(n)?
+        };
+        let el1 = self.curr_it1.peek()?.clone();
+        Some([el1, el2])
+    }
+
+    /// Skips `n` elements in O(1), landing exactly where `n` calls to [`Iterator::next`] would.
+    ///
+    /// Like [`nth`](Hom2FCartProd::nth), this is an inherent method and not an override of
+    /// [`Iterator::advance_by`], so `.skip(n)`/`.step_by(n)` don't benefit from it.
+    ///
+    /// Returns `Ok(())` if `n` elements were available to skip, or `Err(k)` with the shortfall
+    /// `k` if the product was exhausted after skipping only `n - k` elements.
+    pub fn advance_by(&mut self, n: usize) -> Result<(), usize> {
+        if n == 0 {
+            return Ok(());
+        }
+        let remaining = self.remaining();
+        if n <= remaining {
+            self.nth(n - 1);
+            Ok(())
+        } else {
+            if remaining > 0 {
+                self.nth(remaining - 1);
+            }
+            Err(n - remaining)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,4 +305,106 @@ mod tests {
         let it = Hom2FCartProd::new(it1, it2);
         assert_eq!(it.size_hint(), (0, Some(0)));
     }
+
+    #[test]
+    fn test_nth_matches_repeated_next() {
+        for n in 0..12 {
+            let mut by_next = Hom2FCartProd::new(0..4, 0..3);
+            let mut expected = None;
+            for _ in 0..=n {
+                expected = by_next.next();
+            }
+            let mut by_nth = Hom2FCartProd::new(0..4, 0..3);
+            assert_eq!(by_nth.nth(n), expected, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn test_nth_out_of_bounds() {
+        let mut it = Hom2FCartProd::new(0..2, 0..2);
+        assert_eq!(it.nth(4), None);
+    }
+
+    #[test]
+    fn test_nth_empty() {
+        let mut it = Hom2FCartProd::new(0..0, 0..2);
+        assert_eq!(it.nth(0), None);
+    }
+
+    #[test]
+    fn test_advance_by_then_next() {
+        let mut it = Hom2FCartProd::new(0..2, 0..2);
+        assert_eq!(it.advance_by(1), Ok(()));
+        assert_eq!(it.next(), Some([0, 1]));
+    }
+
+    #[test]
+    fn test_advance_by_past_the_end() {
+        let mut it = Hom2FCartProd::new(0..2, 0..2);
+        assert_eq!(it.advance_by(6), Err(2));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_next_back_matches_reversed_collection() {
+        let mut forward = Hom2FCartProd::new(0..3, 0..2);
+        let mut backward = Hom2FCartProd::new(0..3, 0..2);
+        let mut forward_items = [[0, 0]; 6];
+        for slot in forward_items.iter_mut() {
+            *slot = forward.next().unwrap();
+        }
+        assert_eq!(forward.next(), None);
+        for expected in forward_items.iter().rev() {
+            assert_eq!(backward.next_back(), Some(*expected));
+        }
+        assert_eq!(backward.next_back(), None);
+    }
+
+    #[test]
+    fn test_next_back_empty() {
+        let mut it = Hom2FCartProd::new(0..0, 0..2);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn test_meet_in_the_middle() {
+        // 3 * 2 = 6 elements; alternate ends and check they meet exactly without overlap.
+        let mut it = Hom2FCartProd::new(0..3, 0..2);
+        assert_eq!(it.next(), Some([0, 0]));
+        assert_eq!(it.next_back(), Some([2, 1]));
+        assert_eq!(it.next(), Some([0, 1]));
+        assert_eq!(it.next_back(), Some([2, 0]));
+        assert_eq!(it.next(), Some([1, 0]));
+        assert_eq!(it.next_back(), Some([1, 1]));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn test_len_shrinks_as_consumed() {
+        let mut it = Hom2FCartProd::new(0..3, 0..2);
+        assert_eq!(it.len(), 6);
+        it.next();
+        assert_eq!(it.len(), 5);
+        it.next_back();
+        assert_eq!(it.len(), 4);
+    }
+
+    #[test]
+    fn test_fused_after_exhaustion() {
+        let mut it = Hom2FCartProd::new(0..1, 0..1);
+        assert_eq!(it.next(), Some([0, 0]));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_nth_after_next_back_shares_curr_it2() {
+        let mut it = Hom2FCartProd::new(0..1, 0..4);
+        assert_eq!(it.next(), Some([0, 0]));
+        assert_eq!(it.next_back(), Some([0, 3]));
+        assert_eq!(it.nth(0), Some([0, 1]));
+        assert_eq!(it.nth(0), Some([0, 2]));
+        assert_eq!(it.nth(0), None);
+    }
 }
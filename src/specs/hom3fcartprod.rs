@@ -1,4 +1,4 @@
-use core::iter::Peekable;
+use core::iter::{FusedIterator, Peekable};
 
 /// Three-fold [Cartesian product] of [iterators] that are "homogeneous" in the sense that
 /// they iterate over the same type of items. Notice that if the elements in the iterators repeat,
@@ -64,6 +64,20 @@ where
     // the traversal over it1 happens only once.
     orig_it2: I2,
     orig_it3: I3,
+    // Back-traversal state, populated lazily on the first `next_back` call. `back_el1`/`back_el2`
+    // cache it1/it2 values for rows claimed from the back (distinct from the forward cursor's own
+    // rows), since `Peekable` has no way to peek from the back. `None` means back traversal is
+    // still sharing the corresponding forward cursor's row.
+    back_el1: Option<Item>,
+    back_it2: Option<Peekable<I2>>,
+    back_el2: Option<Item>,
+    back_it3: Option<I3>,
+    // Number of elements `next_back` has taken directly from the back of `curr_it2` while it is
+    // shared with the forward cursor (`curr_it1.len() == 1`, no separate `back_it2` row claimed).
+    shared_it2_back_taken: usize,
+    // Likewise for `curr_it3`, while it is shared with the forward cursor (`curr_it1.len() == 1`
+    // *and* `curr_it2.len() == 1`, no separate `back_it3` column claimed).
+    shared_it3_back_taken: usize,
 }
 
 impl<Item, I1, I2, I3> Hom3FCartProd<Item, I1, I2, I3>
@@ -81,6 +95,12 @@ where
             orig_it2: it2,
             curr_it3: it3.clone(),
             orig_it3: it3,
+            back_el1: None,
+            back_it2: None,
+            back_el2: None,
+            back_it3: None,
+            shared_it2_back_taken: 0,
+            shared_it3_back_taken: 0,
         }
     }
 }
@@ -103,12 +123,15 @@ where
         drop(self.curr_it2.next()?);
         if let Some(el2) = self.curr_it2.peek().map(Clone::clone) {
             self.curr_it3 = self.orig_it3.clone();
+            self.shared_it3_back_taken = 0;
             return Some([el1, el2, self.curr_it3.next()?]);
         }
         drop(self.curr_it1.next()?);
         if let Some(el1) = self.curr_it1.peek().map(Clone::clone) {
             self.curr_it3 = self.orig_it3.clone();
+            self.shared_it3_back_taken = 0;
             self.curr_it2 = self.orig_it2.clone().peekable();
+            self.shared_it2_back_taken = 0;
             el2 = self.curr_it2.peek()?.clone();
             return Some([el1, el2, self.curr_it3.next()?]);
         }
@@ -134,6 +157,252 @@ where
     }
 }
 
+/// `next` returns `None` as soon as `it1` is exhausted and never touches `it1`/`it2`/`it3` again
+/// afterwards, so a [`Hom3FCartProd`] never yields `Some` after `None`.
+impl<Item, I1, I2, I3> FusedIterator for Hom3FCartProd<Item, I1, I2, I3>
+where
+    Item: Clone,
+    I1: Iterator<Item=Item>,
+    I2: Iterator<Item=Item> + Clone,
+    I3: Iterator<Item=Item> + Clone,
+{
+}
+
+impl<Item, I1, I2, I3> ExactSizeIterator for Hom3FCartProd<Item, I1, I2, I3>
+where
+    Item: Clone,
+    I1: Iterator<Item=Item> + ExactSizeIterator,
+    I2: Iterator<Item=Item> + Clone + ExactSizeIterator,
+    I3: Iterator<Item=Item> + Clone + ExactSizeIterator,
+{
+    fn len(&self) -> usize {
+        self.remaining()
+    }
+}
+
+impl<Item, I1, I2, I3> DoubleEndedIterator for Hom3FCartProd<Item, I1, I2, I3>
+where
+    Item: Clone,
+    I1: Iterator<Item=Item> + ExactSizeIterator + DoubleEndedIterator,
+    I2: Iterator<Item=Item> + Clone + ExactSizeIterator + DoubleEndedIterator,
+    I3: Iterator<Item=Item> + Clone + ExactSizeIterator + DoubleEndedIterator,
+{
+    /// Emits the lexicographically-greatest remaining tuple, walking the same "meet in the
+    /// middle" scheme as [`Hom2FCartProd`](super::Hom2FCartProd)'s `next_back` one axis deeper:
+    /// `back_el1`/`back_it2` claim a row of `it1` distinct from the forward cursor's, and within
+    /// that row `back_el2`/`back_it3` claim a row of `it2` in turn. Once `it1` has only one row
+    /// left, its `it2` axis (`curr_it2`) is driven from both ends directly, relying on
+    /// `DoubleEndedIterator`/[`Peekable`] semantics to meet correctly without double-yielding.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match (self.back_el1.is_some(), self.back_el2.is_some()) {
+                (false, _) => match self.curr_it1.len() {
+                    0 => return None,
+                    1 => {
+                        // Sharing it1's row with the forward cursor; drive it2 from both ends.
+                        let el1 = self.curr_it1.peek()?.clone();
+                        if self.back_el2.is_none() {
+                            match self.curr_it2.len() {
+                                0 => return None,
+                                1 => {
+                                    let el2 = self.curr_it2.peek()?.clone();
+                                    let el3 = self.curr_it3.next_back()?;
+                                    self.shared_it3_back_taken += 1;
+                                    return Some([el1, el2, el3]);
+                                }
+                                _ => {
+                                    let el2 = self.curr_it2.next_back()?;
+                                    self.shared_it2_back_taken += 1;
+                                    self.back_el2 = Some(el2);
+                                    self.back_it3 = Some(self.orig_it3.clone());
+                                }
+                            }
+                        } else {
+                            let el2 = self.back_el2.clone().expect("checked is_some above");
+                            match self.back_it3.as_mut().expect("set alongside back_el2").next_back() {
+                                Some(el3) => return Some([el1, el2, el3]),
+                                None => {
+                                    self.back_el2 = None;
+                                    self.back_it3 = None;
+                                }
+                            }
+                        }
+                    }
+                    _ => {
+                        // Claim a fresh it1 row strictly to the right of the forward cursor's row.
+                        let el1 = self.curr_it1.next_back()?;
+                        self.back_el1 = Some(el1);
+                        self.back_it2 = Some(self.orig_it2.clone().peekable());
+                        self.back_el2 = None;
+                        self.back_it3 = None;
+                    }
+                },
+                (true, false) => {
+                    // Within a claimed it1 row, try to claim the next it2 row from its back.
+                    match self.back_it2.as_mut().expect("set alongside back_el1").next_back() {
+                        Some(el2) => {
+                            self.back_el2 = Some(el2);
+                            self.back_it3 = Some(self.orig_it3.clone());
+                        }
+                        None => {
+                            // This claimed it1 row is fully drained; release it and re-evaluate.
+                            self.back_el1 = None;
+                            self.back_it2 = None;
+                        }
+                    }
+                }
+                (true, true) => {
+                    let el1 = self.back_el1.clone().expect("checked is_some above");
+                    let el2 = self.back_el2.clone().expect("checked is_some above");
+                    match self.back_it3.as_mut().expect("set alongside back_el2").next_back() {
+                        Some(el3) => return Some([el1, el2, el3]),
+                        None => {
+                            self.back_el2 = None;
+                            self.back_it3 = None;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<Item, I1, I2, I3> Hom3FCartProd<Item, I1, I2, I3>
+where
+    Item: Clone,
+    I1: Iterator<Item=Item> + ExactSizeIterator,
+    I2: Iterator<Item=Item> + Clone + ExactSizeIterator,
+    I3: Iterator<Item=Item> + Clone + ExactSizeIterator,
+{
+    /// Number of elements remaining, computed from the three axes' exact lengths instead of by
+    /// counting. Used by [`Hom3FCartProd::advance_by`] and by [`ExactSizeIterator::len`].
+    ///
+    /// A row claimed from the back by [`next_back`](Hom3FCartProd::next_back) (tracked via
+    /// `back_el1`/`back_it2`) is no longer reflected by `curr_it1`'s length, and likewise a
+    /// column claimed from the back within either the shared or the claimed row (tracked via
+    /// `back_el2`/`back_it3`) is no longer reflected by the corresponding `it2` cursor's length;
+    /// both are added back in here.
+    fn remaining(&self) -> usize {
+        let r2 = self.orig_it2.len();
+        let r3 = self.orig_it3.len();
+
+        let claimed_row_remaining = if self.back_el1.is_some() {
+            let it2_rows_left = self.back_it2.as_ref().map_or(0, ExactSizeIterator::len);
+            let claimed_col_remaining = if self.back_el2.is_some() {
+                self.back_it3.as_ref().map_or(0, ExactSizeIterator::len)
+            } else {
+                0
+            };
+            it2_rows_left * r3 + claimed_col_remaining
+        } else {
+            0
+        };
+
+        let rows_left = self.curr_it1.len();
+        let front_remaining = if rows_left == 0 {
+            0
+        } else {
+            let cols_left = self.curr_it2.len();
+            if cols_left == 0 {
+                (rows_left - 1) * r2 * r3
+            } else {
+                let shared_claimed_col_remaining = if self.back_el1.is_none() && self.back_el2.is_some() {
+                    self.back_it3.as_ref().map_or(0, ExactSizeIterator::len)
+                } else {
+                    0
+                };
+                self.curr_it3.len() + (cols_left - 1) * r3 + shared_claimed_col_remaining
+                    + (rows_left - 1) * r2 * r3
+            }
+        };
+
+        front_remaining + claimed_row_remaining
+    }
+
+    /// O(1) equivalent of calling [`Iterator::next`] `n` times and keeping the last result,
+    /// available whenever all three axes are [`ExactSizeIterator`].
+    ///
+    /// This is an inherent method, not an override of [`Iterator::nth`], so it only speeds up
+    /// direct calls to `nth`/[`advance_by`](Hom3FCartProd::advance_by) — `.skip(n)`/`.step_by(n)`
+    /// go through the default trait method and still drive the axes with plain `next` calls.
+    ///
+    /// Treats the product as a mixed-radix number whose least-significant digit is `it3`: the
+    /// skip count is decomposed into a carry into `it1` (advanced directly, since it is never
+    /// reset), an offset into `it2`, and an offset into `it3`. `d2_start`/`d3_start` recover how
+    /// far `it2`/`it3` have been consumed from the *front* alone (subtracting out whatever
+    /// `next_back` already took from their back while shared with the forward cursor), and `it2`/
+    /// `it3` are only reset to a fresh clone when the row/column they hold actually changes, so
+    /// any already-claimed back state in a still-current row/column stays intact.
+    pub fn nth(&mut self, n: usize) -> Option<[Item; 3]> {
+        let r2 = self.orig_it2.len();
+        let r3 = self.orig_it3.len();
+        if r2 == 0 || r3 == 0 {
+            return None;
+        }
+        self.curr_it1.peek()?;
+
+        let d2_start = r2 - self.curr_it2.len() - self.shared_it2_back_taken;
+        let d3_start = r3 - self.curr_it3.len() - self.shared_it3_back_taken;
+        let rem = d3_start.checked_add(n)?.checked_add(d2_start.checked_mul(r3)?)?;
+        let d3 = rem % r3;
+        let q = rem / r3;
+        let d2 = q % r2;
+        let carry = q / r2;
+
+        let el3 = if carry > 0 {
+            // New it1 row: it2 and it3 both restart fresh.
+            self.curr_it1.nth(carry - 1)?;
+            self.curr_it1.peek()?;
+            self.curr_it2 = self.orig_it2.clone().peekable();
+            self.shared_it2_back_taken = 0;
+            if d2 > 0 {
+                self.curr_it2.nth(d2 - 1)?;
+            }
+            self.curr_it2.peek()?;
+            self.curr_it3 = self.orig_it3.clone();
+            self.shared_it3_back_taken = 0;
+            self.curr_it3.nth(d3)?
+        } else if d2 > d2_start {
+            // Same it1 row, new it2 column: it3 restarts fresh, it2 advances from where it is.
+            self.curr_it2.nth(d2 - d2_start - 1)?;
+            self.curr_it2.peek()?;
+            self.curr_it3 = self.orig_it3.clone();
+            self.shared_it3_back_taken = 0;
+            self.curr_it3.nth(d3)?
+        } else {
+            // Same it1 row and it2 column: just advance it3 from where it is.
+            self.curr_it3.nth(d3 - d3_start)?
+        };
+
+        let el2 = self.curr_it2.peek()?.clone();
+        let el1 = self.curr_it1.peek()?.clone();
+        Some([el1, el2, el3])
+    }
+
+    /// Skips `n` elements in O(1), landing exactly where `n` calls to [`Iterator::next`] would.
+    ///
+    /// Like [`nth`](Hom3FCartProd::nth), this is an inherent method and not an override of
+    /// [`Iterator::advance_by`], so `.skip(n)`/`.step_by(n)` don't benefit from it.
+    ///
+    /// Returns `Ok(())` if `n` elements were available to skip, or `Err(k)` with the shortfall
+    /// `k` if the product was exhausted after skipping only `n - k` elements.
+    pub fn advance_by(&mut self, n: usize) -> Result<(), usize> {
+        if n == 0 {
+            return Ok(());
+        }
+        let remaining = self.remaining();
+        if n <= remaining {
+            self.nth(n - 1);
+            Ok(())
+        } else {
+            if remaining > 0 {
+                self.nth(remaining - 1);
+            }
+            Err(n - remaining)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,4 +466,117 @@ mod tests {
         let it = Hom3FCartProd::new(it1, it2, it3);
         assert_eq!(it.size_hint(), (0, Some(0)));
     }
+
+    #[test]
+    fn test_nth_matches_repeated_next() {
+        for n in 0..24 {
+            let mut by_next = Hom3FCartProd::new(0..4, 0..3, 0..2);
+            let mut expected = None;
+            for _ in 0..=n {
+                expected = by_next.next();
+            }
+            let mut by_nth = Hom3FCartProd::new(0..4, 0..3, 0..2);
+            assert_eq!(by_nth.nth(n), expected, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn test_nth_out_of_bounds() {
+        let mut it = Hom3FCartProd::new(0..2, 0..2, 0..2);
+        assert_eq!(it.nth(8), None);
+    }
+
+    #[test]
+    fn test_nth_empty() {
+        let mut it = Hom3FCartProd::new(0..2, 0..2, 0..0);
+        assert_eq!(it.nth(0), None);
+    }
+
+    #[test]
+    fn test_advance_by_then_next() {
+        let mut it = Hom3FCartProd::new(0..2, 0..2, 0..2);
+        assert_eq!(it.advance_by(3), Ok(()));
+        assert_eq!(it.next(), Some([0, 1, 1]));
+    }
+
+    #[test]
+    fn test_advance_by_past_the_end() {
+        let mut it = Hom3FCartProd::new(0..2, 0..2, 0..2);
+        assert_eq!(it.advance_by(20), Err(12));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_next_back_matches_reversed_collection() {
+        let mut forward = Hom3FCartProd::new(0..3, 0..2, 0..2);
+        let mut backward = Hom3FCartProd::new(0..3, 0..2, 0..2);
+        let mut forward_items = [[0, 0, 0]; 12];
+        for slot in forward_items.iter_mut() {
+            *slot = forward.next().unwrap();
+        }
+        assert_eq!(forward.next(), None);
+        for expected in forward_items.iter().rev() {
+            assert_eq!(backward.next_back(), Some(*expected));
+        }
+        assert_eq!(backward.next_back(), None);
+    }
+
+    #[test]
+    fn test_next_back_empty() {
+        let mut it = Hom3FCartProd::new(0..2, 0..2, 0..0);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn test_meet_in_the_middle() {
+        // 2 * 2 * 2 = 8 elements; alternate ends and check they meet exactly without overlap.
+        let mut it = Hom3FCartProd::new(0..2, 0..2, 0..2);
+        assert_eq!(it.next(), Some([0, 0, 0]));
+        assert_eq!(it.next_back(), Some([1, 1, 1]));
+        assert_eq!(it.next(), Some([0, 0, 1]));
+        assert_eq!(it.next_back(), Some([1, 1, 0]));
+        assert_eq!(it.next(), Some([0, 1, 0]));
+        assert_eq!(it.next_back(), Some([1, 0, 1]));
+        assert_eq!(it.next(), Some([0, 1, 1]));
+        assert_eq!(it.next_back(), Some([1, 0, 0]));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn test_len_shrinks_as_consumed() {
+        let mut it = Hom3FCartProd::new(0..2, 0..2, 0..2);
+        assert_eq!(it.len(), 8);
+        it.next();
+        assert_eq!(it.len(), 7);
+        it.next_back();
+        assert_eq!(it.len(), 6);
+    }
+
+    #[test]
+    fn test_fused_after_exhaustion() {
+        let mut it = Hom3FCartProd::new(0..1, 0..1, 0..1);
+        assert_eq!(it.next(), Some([0, 0, 0]));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_nth_after_next_back_shares_curr_it3() {
+        let mut it = Hom3FCartProd::new(0..1, 0..1, 0..4);
+        assert_eq!(it.next(), Some([0, 0, 0]));
+        assert_eq!(it.next_back(), Some([0, 0, 3]));
+        assert_eq!(it.nth(0), Some([0, 0, 1]));
+        assert_eq!(it.nth(0), Some([0, 0, 2]));
+        assert_eq!(it.nth(0), None);
+    }
+
+    #[test]
+    fn test_nth_after_next_back_shares_curr_it2() {
+        let mut it = Hom3FCartProd::new(0..1, 0..3, 0..1);
+        assert_eq!(it.next(), Some([0, 0, 0]));
+        assert_eq!(it.next_back(), Some([0, 2, 0]));
+        assert_eq!(it.nth(0), Some([0, 1, 0]));
+        assert_eq!(it.nth(0), None);
+    }
 }
\ No newline at end of file
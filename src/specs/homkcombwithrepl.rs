@@ -0,0 +1,240 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// `K`-combinations with replacement from a single homogeneous [iterator]: the restricted
+/// [Cartesian product] where order doesn't matter and repeats are allowed, mirroring itertools'
+/// `combinations_with_replacement`.
+///
+/// The input is collected into a pool once (every position can reuse any pool element, so the
+/// pool must outlive the whole traversal), and each yielded `[Item; K]` has non-decreasing
+/// pool-indices, i.e. `i1 <= i2 <= ... <= iK`.
+///
+/// ## Examples
+///
+/// ### Manual iteration
+///
+/// ```
+/// use cart_prod::specs::HomKCombWithRepl;
+///
+/// let mut it = HomKCombWithRepl::<_, 2>::new(0..3);
+///
+/// assert_eq!(it.next(), Some([0, 0]));
+/// assert_eq!(it.next(), Some([0, 1]));
+/// assert_eq!(it.next(), Some([0, 2]));
+/// assert_eq!(it.next(), Some([1, 1]));
+/// assert_eq!(it.next(), Some([1, 2]));
+/// assert_eq!(it.next(), Some([2, 2]));
+/// assert_eq!(it.next(), None);
+/// ```
+///
+/// ### For loop with pattern matching
+///
+/// ```
+/// use cart_prod::specs::HomKCombWithRepl;
+/// use core::fmt::Write;
+///
+/// let mut s = String::new();
+///
+/// for [el1, el2] in HomKCombWithRepl::<_, 2>::new(0..=1) {
+///    // The panic is intentional to keep the example simple.
+///    writeln!(s, "{el1} {el2}").unwrap();
+/// }
+///
+/// assert_eq!(s, "0 0\n0 1\n1 1\n");
+/// ```
+///
+/// [Cartesian product]: https://en.wikipedia.org/wiki/Cartesian_product
+/// [iterator]: https://doc.rust-lang.org/book/ch13-02-iterators.html
+pub struct HomKCombWithRepl<Item, const K: usize>
+where
+    Item: Clone,
+{
+    pool: Vec<Item>,
+    // Current combination as non-decreasing indices into `pool`; `None` once exhausted.
+    indices: Option<[usize; K]>,
+    // Exact count of combinations left to yield, when it fits in a `usize`.
+    remaining: Option<usize>,
+}
+
+impl<Item, const K: usize> HomKCombWithRepl<Item, K>
+where
+    Item: Clone,
+{
+    /// Creates a new [`HomKCombWithRepl`] by collecting `iter` into a pool and starting at the
+    /// lexicographically-smallest combination `[0, 0, ..., 0]`.
+    ///
+    /// An empty pool with `K > 0` has no valid combination, so the iterator starts (and stays)
+    /// exhausted.
+    pub fn new<I: Iterator<Item = Item>>(iter: I) -> Self {
+        let pool: Vec<Item> = iter.collect();
+        let n = pool.len();
+        if n == 0 && K > 0 {
+            Self {
+                pool,
+                indices: None,
+                remaining: Some(0),
+            }
+        } else {
+            Self {
+                pool,
+                indices: Some([0usize; K]),
+                remaining: Self::exact_count(n),
+            }
+        }
+    }
+
+    /// Computes `C(n + K - 1, K)`, the number of non-decreasing `K`-tuples of indices into a
+    /// pool of `n` elements, via the incremental `result = result * (n + i) / (i + 1)` recurrence
+    /// (exact at every step), guarding each multiplication and division with checked arithmetic
+    /// so that large pools report an unknown bound instead of silently wrapping.
+    fn exact_count(n: usize) -> Option<usize> {
+        let mut result = 1usize;
+        for i in 0..K {
+            result = result.checked_mul(n.checked_add(i)?)?;
+            result = result.checked_div(i.checked_add(1)?)?;
+        }
+        Some(result)
+    }
+}
+
+impl<Item, const K: usize> Iterator for HomKCombWithRepl<Item, K>
+where
+    Item: Clone,
+{
+    type Item = [Item; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices?;
+        let item = indices.map(|i| self.pool[i].clone());
+        self.remaining = self.remaining.map(|r| r.saturating_sub(1));
+
+        // Advance like an odometer with a non-decreasing floor: scan from the rightmost position
+        // for the first index that can still grow, bump it, and set every index to its right to
+        // that same new value (not to zero), which is what keeps the tuple non-decreasing.
+        let n = self.pool.len();
+        let mut pos = K;
+        self.indices = loop {
+            if pos == 0 {
+                break None;
+            }
+            pos -= 1;
+            if indices[pos] < n - 1 {
+                let mut next_indices = indices;
+                next_indices[pos] += 1;
+                let floor = next_indices[pos];
+                for slot in next_indices[(pos + 1)..].iter_mut() {
+                    *slot = floor;
+                }
+                break Some(next_indices);
+            }
+        };
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(r) => (r, Some(r)),
+            None => (0, None),
+        }
+    }
+}
+
+/// `next` returns `None` as soon as `indices` is `None` and never touches it again afterwards,
+/// so a [`HomKCombWithRepl`] never yields `Some` after `None`.
+impl<Item, const K: usize> FusedIterator for HomKCombWithRepl<Item, K> where Item: Clone {}
+
+impl<Item, const K: usize> HomKCombWithRepl<Item, K>
+where
+    Item: Clone,
+{
+    /// Returns the already-tracked `remaining` counter directly, when the total combination count
+    /// fits in a `usize`.
+    ///
+    /// This is deliberately an inherent method, not an [`ExactSizeIterator`] impl: for a large
+    /// enough pool/`K`, [`HomKCombWithRepl::exact_count`] can overflow `usize` at construction
+    /// time, and `ExactSizeIterator::len` is documented to never panic — a contract this type
+    /// can't uphold unconditionally, since whether the count overflows depends on runtime values
+    /// that no bound on `Item`/`K` can rule out at compile time. Callers who know their pool/`K`
+    /// combination is small enough can call this directly instead.
+    pub fn len(&self) -> usize {
+        self.remaining
+            .expect("combination count overflowed usize; HomKCombWithRepl::len is not exact here")
+    }
+
+    /// Returns `true` if there are no combinations left to yield.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairs() {
+        let mut it = HomKCombWithRepl::<_, 2>::new(0..3);
+        assert_eq!(it.next(), Some([0, 0]));
+        assert_eq!(it.next(), Some([0, 1]));
+        assert_eq!(it.next(), Some([0, 2]));
+        assert_eq!(it.next(), Some([1, 1]));
+        assert_eq!(it.next(), Some([1, 2]));
+        assert_eq!(it.next(), Some([2, 2]));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_triples() {
+        let mut it = HomKCombWithRepl::<_, 3>::new(0..2);
+        assert_eq!(it.next(), Some([0, 0, 0]));
+        assert_eq!(it.next(), Some([0, 0, 1]));
+        assert_eq!(it.next(), Some([0, 1, 1]));
+        assert_eq!(it.next(), Some([1, 1, 1]));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_empty_pool_with_k_gt_zero() {
+        let mut it = HomKCombWithRepl::<i32, 2>::new(0..0);
+        assert_eq!(it.next(), None);
+        assert_eq!(it.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_k_zero_yields_one_empty_tuple() {
+        let mut it = HomKCombWithRepl::<i32, 0>::new(0..3);
+        assert_eq!(it.next(), Some([]));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_size_hint_simple() {
+        let it = HomKCombWithRepl::<_, 2>::new(0..3);
+        assert_eq!(it.size_hint(), (6, Some(6)));
+    }
+
+    #[test]
+    fn test_size_hint_shrinks_as_consumed() {
+        let mut it = HomKCombWithRepl::<_, 2>::new(0..3);
+        assert_eq!(it.size_hint(), (6, Some(6)));
+        it.next();
+        assert_eq!(it.size_hint(), (5, Some(5)));
+    }
+
+    #[test]
+    fn test_len_shrinks_as_consumed() {
+        let mut it = HomKCombWithRepl::<_, 2>::new(0..3);
+        assert_eq!(it.len(), 6);
+        it.next();
+        assert_eq!(it.len(), 5);
+    }
+
+    #[test]
+    fn test_fused_after_exhaustion() {
+        let mut it = HomKCombWithRepl::<_, 2>::new(0..1);
+        assert_eq!(it.next(), Some([0, 0]));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+    }
+}
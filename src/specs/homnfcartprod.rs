@@ -0,0 +1,320 @@
+use core::iter::{FusedIterator, Peekable};
+
+/// `N`-fold [Cartesian product] of [iterators] that are "homogeneous" in the sense that
+/// they all iterate over the same type of items. Notice that if the elements in the iterators
+/// repeat, the resulting iterator will repeat as well.
+///
+/// Unlike [`Hom2FCartProd`] and [`Hom3FCartProd`], which hard-code the number of axes,
+/// [`HomNFCartProd`] takes the iterators as an array `[I; N]` and subsumes both (and any
+/// other k-fold product) in a single type.
+///
+/// ## Examples
+///
+/// ### Manual iteration
+///
+/// ```
+/// use cart_prod::specs::HomNFCartProd;
+///
+/// let it1 = 0..=1;
+/// let it2 = 0..=1;
+///
+/// let mut it = HomNFCartProd::new([it1, it2]);
+///
+/// assert_eq!(it.next(), Some([0, 0]));
+/// assert_eq!(it.next(), Some([0, 1]));
+/// assert_eq!(it.next(), Some([1, 0]));
+/// assert_eq!(it.next(), Some([1, 1]));
+/// assert_eq!(it.next(), None);
+/// ```
+///
+/// ### For loop with pattern matching
+///
+/// ```
+/// use cart_prod::specs::HomNFCartProd;
+/// use core::fmt::Write;
+///
+/// let it1 = 0..=1;
+/// let it2 = 0..=1;
+/// let it3 = 0..=1;
+///
+/// let mut s = String::new();
+///
+/// for [el1, el2, el3] in HomNFCartProd::new([it1, it2, it3]) {
+///    // The panic is intentional to keep the example simple.
+///    writeln!(s, "{el1} {el2} {el3}").unwrap();
+/// }
+///
+/// assert_eq!(s, "0 0 0\n0 0 1\n0 1 0\n0 1 1\n1 0 0\n1 0 1\n1 1 0\n1 1 1\n");
+/// ```
+///
+/// Unlike [`Hom2FCartProd`] and [`Hom3FCartProd`], which only require `Clone` on the
+/// non-leftmost axes (the leftmost is traversed once and never reset), [`HomNFCartProd`] stores
+/// all `N` axes in a single homogeneous `[I; N]`. Since every element of that array shares the
+/// same type `I`, the `Clone` bound applies uniformly and can't be waived just for index `0`
+/// — a capability regression for callers with a non-`Clone` leftmost iterator, accepted here as
+/// the cost of subsuming an arbitrary arity in one type.
+///
+/// For the same reason, [`HomNFCartProd`] also doesn't (yet) have the `DoubleEndedIterator`
+/// `next_back` or the O(1) `nth`/`advance_by` that [`Hom2FCartProd`] and [`Hom3FCartProd`] have:
+/// those rely on per-axis back-traversal bookkeeping that was only ever written out for 2 and 3
+/// fixed axes, not generalized to `[I; N]`. So for `N = 2` or `N = 3`, this type is strictly
+/// weaker than the fixed-arity one it could otherwise replace — reach for [`Hom2FCartProd`]/
+/// [`Hom3FCartProd`] directly when that matters.
+///
+/// [Cartesian product]: https://en.wikipedia.org/wiki/Cartesian_product
+/// [iterators]: https://doc.rust-lang.org/book/ch13-02-iterators.html
+/// [`Hom2FCartProd`]: crate::specs::Hom2FCartProd
+/// [`Hom3FCartProd`]: crate::specs::Hom3FCartProd
+pub struct HomNFCartProd<Item, I, const N: usize>
+where
+    Item: Clone,
+    I: Iterator<Item = Item> + Clone,
+{
+    curr: [Peekable<I>; N],
+    // Pristine clones used to reset an axis once it is exhausted and the carry moves past it.
+    // `orig[0]` is `None` because the leftmost axis is never reset: once it is exhausted, the
+    // whole product is done. Keeping it `None` instead of a clone avoids paying for a clone of
+    // the leftmost iterator that would provably never be read.
+    orig: [Option<I>; N],
+    // Only meaningful when `N == 0`: with no axes there is no per-axis state to exhaust, yet the
+    // 0-fold product still has exactly one tuple (the empty one) to yield. Tracks whether that
+    // single tuple has already been produced.
+    zero_arity_done: bool,
+}
+
+impl<Item, I, const N: usize> HomNFCartProd<Item, I, N>
+where
+    Item: Clone,
+    I: Iterator<Item = Item> + Clone,
+{
+    /// Creates a new [`HomNFCartProd`] from an array of `N` iterators.
+    pub fn new(iters: [I; N]) -> Self {
+        // `orig[0]` is never read (see the field comment), so skip cloning index 0 entirely.
+        let orig: [Option<I>; N] =
+            core::array::from_fn(|i| if i == 0 { None } else { Some(iters[i].clone()) });
+        Self {
+            orig,
+            curr: iters.map(Iterator::peekable),
+            zero_arity_done: false,
+        }
+    }
+}
+
+impl<Item, I, const N: usize> Iterator for HomNFCartProd<Item, I, N>
+where
+    Item: Clone,
+    I: Iterator<Item = Item> + Clone,
+{
+    type Item = [Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if N == 0 {
+            // No axes to peek or carry through: `[Item; 0]` is trivially the unique tuple, and
+            // `core::array::from_fn` never invokes its closure for a zero-length array.
+            if self.zero_arity_done {
+                return None;
+            }
+            self.zero_arity_done = true;
+            return Some(core::array::from_fn(|_| unreachable!("N == 0")));
+        }
+
+        let peeked: [Option<Item>; N] = core::array::from_fn(|i| self.curr[i].peek().cloned());
+        if peeked.iter().any(Option::is_none) {
+            return None;
+        }
+        let item = peeked.map(|el| el.expect("just checked that every axis has a peeked item"));
+
+        // Advance the rightmost axis, resetting and carrying leftward as axes exhaust.
+        let mut i = N - 1;
+        loop {
+            self.curr[i].next();
+            if self.curr[i].peek().is_some() || i == 0 {
+                break;
+            }
+            self.curr[i] = self.orig[i]
+                .clone()
+                .expect("only reset for i > 0, where orig[i] is always Some")
+                .peekable();
+            i -= 1;
+        }
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if N == 0 {
+            let remaining = usize::from(!self.zero_arity_done);
+            return (remaining, Some(remaining));
+        }
+
+        let mut min = 1usize;
+        let mut max = Some(1usize);
+        for it in &self.curr {
+            let (it_min, it_max) = it.size_hint();
+            min = min.saturating_mul(it_min);
+            max = match (max, it_max) {
+                (Some(max), Some(it_max)) => max.checked_mul(it_max),
+                _ => None,
+            };
+        }
+        (min, max)
+    }
+}
+
+/// `next` returns `None` as soon as any axis is exhausted and never touches `curr`/`orig` again
+/// afterwards, so a [`HomNFCartProd`] never yields `Some` after `None`.
+impl<Item, I, const N: usize> FusedIterator for HomNFCartProd<Item, I, N>
+where
+    Item: Clone,
+    I: Iterator<Item = Item> + Clone,
+{
+}
+
+impl<Item, I, const N: usize> ExactSizeIterator for HomNFCartProd<Item, I, N>
+where
+    Item: Clone,
+    I: Iterator<Item = Item> + Clone + ExactSizeIterator,
+{
+    /// Mixed-radix remaining count: the current axis' own remaining elements, plus each axis to
+    /// its left contributing one full pristine sweep of every axis to *its* right for each of its
+    /// own not-yet-reached positions (mirroring [`Hom3FCartProd`](super::Hom3FCartProd)'s
+    /// `remaining` one axis further).
+    fn len(&self) -> usize {
+        if N == 0 {
+            return usize::from(!self.zero_arity_done);
+        }
+        if self.curr.iter().any(|it| it.len() == 0) {
+            return 0;
+        }
+        let mut remaining = self.curr[N - 1].len();
+        let mut weight = 1usize;
+        for i in (0..N - 1).rev() {
+            weight *= self.orig[i + 1]
+                .as_ref()
+                .expect("index i + 1 >= 1, where orig is always Some")
+                .len();
+            remaining += weight * (self.curr[i].len() - 1);
+        }
+        remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_empty() {
+        let it1 = 0..0;
+        let it2 = 0..2;
+        let mut it = HomNFCartProd::new([it1, it2]);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_two_fold() {
+        let it1 = 0..=1;
+        let it2 = 0..=1;
+        let mut it = HomNFCartProd::new([it1, it2]);
+        assert_eq!(it.next(), Some([0, 0]));
+        assert_eq!(it.next(), Some([0, 1]));
+        assert_eq!(it.next(), Some([1, 0]));
+        assert_eq!(it.next(), Some([1, 1]));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_three_fold() {
+        let it1 = 0..=3;
+        let it2 = 0..=2;
+        let it3 = 0..=1;
+        let mut it = HomNFCartProd::new([it1, it2, it3]);
+        assert_eq!(it.next(), Some([0, 0, 0]));
+        assert_eq!(it.next(), Some([0, 0, 1]));
+        assert_eq!(it.next(), Some([0, 1, 0]));
+        assert_eq!(it.next(), Some([0, 1, 1]));
+        assert_eq!(it.next(), Some([0, 2, 0]));
+        assert_eq!(it.next(), Some([0, 2, 1]));
+        assert_eq!(it.next(), Some([1, 0, 0]));
+        assert_eq!(it.next(), Some([1, 0, 1]));
+        assert_eq!(it.next(), Some([1, 1, 0]));
+        assert_eq!(it.next(), Some([1, 1, 1]));
+        assert_eq!(it.next(), Some([1, 2, 0]));
+        assert_eq!(it.next(), Some([1, 2, 1]));
+        assert_eq!(it.next(), Some([2, 0, 0]));
+        assert_eq!(it.next(), Some([2, 0, 1]));
+        assert_eq!(it.next(), Some([2, 1, 0]));
+        assert_eq!(it.next(), Some([2, 1, 1]));
+        assert_eq!(it.next(), Some([2, 2, 0]));
+        assert_eq!(it.next(), Some([2, 2, 1]));
+        assert_eq!(it.next(), Some([3, 0, 0]));
+        assert_eq!(it.next(), Some([3, 0, 1]));
+        assert_eq!(it.next(), Some([3, 1, 0]));
+        assert_eq!(it.next(), Some([3, 1, 1]));
+        assert_eq!(it.next(), Some([3, 2, 0]));
+        assert_eq!(it.next(), Some([3, 2, 1]));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_four_fold() {
+        let it = HomNFCartProd::new([0..=1, 0..=1, 0..=1, 0..=1]);
+        let mut count = 0;
+        for tuple in it {
+            assert_eq!(tuple.len(), 4);
+            count += 1;
+        }
+        assert_eq!(count, 16);
+    }
+
+    #[test]
+    fn test_size_hint_simple() {
+        let it1 = 0..2;
+        let it2 = 0..2;
+        let it3 = 0..3;
+        let it = HomNFCartProd::new([it1, it2, it3]);
+        assert_eq!(it.size_hint(), (12, Some(12)));
+    }
+
+    #[test]
+    fn test_size_hint_empty() {
+        let it1 = 0..2;
+        let it2 = 0..2;
+        let it3 = 0..0;
+        let it = HomNFCartProd::new([it1, it2, it3]);
+        assert_eq!(it.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_len_shrinks_as_consumed() {
+        let mut it = HomNFCartProd::new([0..2, 0..2, 0..3]);
+        assert_eq!(it.len(), 12);
+        it.next();
+        assert_eq!(it.len(), 11);
+    }
+
+    #[test]
+    fn test_fused_after_exhaustion() {
+        let mut it = HomNFCartProd::new([0..1, 0..1]);
+        assert_eq!(it.next(), Some([0, 0]));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_zero_arity_yields_one_empty_tuple() {
+        let mut it = HomNFCartProd::<i32, core::ops::Range<i32>, 0>::new([]);
+        assert_eq!(it.next(), Some([]));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_zero_arity_size_hint_and_len() {
+        let mut it = HomNFCartProd::<i32, core::ops::Range<i32>, 0>::new([]);
+        assert_eq!(it.size_hint(), (1, Some(1)));
+        assert_eq!(it.len(), 1);
+        it.next();
+        assert_eq!(it.size_hint(), (0, Some(0)));
+        assert_eq!(it.len(), 0);
+    }
+}
@@ -0,0 +1,13 @@
+mod het2fcartprod;
+mod het3fcartprod;
+mod hom2fcartprod;
+mod hom3fcartprod;
+mod homkcombwithrepl;
+mod homnfcartprod;
+
+pub use het2fcartprod::Het2FCartProd;
+pub use het3fcartprod::Het3FCartProd;
+pub use hom2fcartprod::Hom2FCartProd;
+pub use hom3fcartprod::Hom3FCartProd;
+pub use homkcombwithrepl::HomKCombWithRepl;
+pub use homnfcartprod::HomNFCartProd;